@@ -1,13 +1,36 @@
-use std::{fs, path::PathBuf};
+use std::{fs, path::PathBuf, time::Duration};
 
 use dirs;
 use serde::{Deserialize, Serialize};
 
+const DEFAULT_USER_AGENT: &str = concat!("LazyIssues/", env!("CARGO_PKG_VERSION"));
+const DEFAULT_FRESHNESS_WINDOW_SECS: u64 = 60;
+const DEFAULT_LOADING_TEXT: &str = "loading";
+
 #[derive(Serialize, Deserialize)]
 pub struct Config {
     is_default_config: bool,
 
     github_token_file_path: String,
+
+    #[serde(default)]
+    user_agent: Option<String>,
+
+    #[serde(default)]
+    no_color: bool,
+
+    #[serde(default)]
+    freshness_window_secs: Option<u64>,
+
+    #[serde(default)]
+    spinner_frames: Option<Vec<String>>,
+
+    #[serde(default)]
+    loading_text: Option<String>,
+    #[serde(default)]
+    menu_order: Option<Vec<String>>,
+    #[serde(default)]
+    enabled_views: Option<Vec<String>>,
 }
 
 impl Config {
@@ -15,6 +38,13 @@ impl Config {
         Self {
             is_default_config: true,
             github_token_file_path: "".to_string(),
+            user_agent: None,
+            no_color: false,
+            freshness_window_secs: None,
+            spinner_frames: None,
+            loading_text: None,
+            menu_order: None,
+            enabled_views: None,
         }
     }
 
@@ -22,6 +52,44 @@ impl Config {
         self.is_default_config
     }
 
+    // some proxies filter on the user agent so it can be overwritten in the config
+    pub fn get_user_agent(&self) -> &str {
+        self.user_agent.as_deref().unwrap_or(DEFAULT_USER_AGENT)
+    }
+
+    pub fn is_no_color(&self) -> bool {
+        self.no_color
+    }
+
+    // switching back to a view within this window reuses the data we already have
+    pub fn get_freshness_window(&self) -> Duration {
+        Duration::from_secs(
+            self.freshness_window_secs
+                .unwrap_or(DEFAULT_FRESHNESS_WINDOW_SECS),
+        )
+    }
+
+    // an empty list of frames would leave nothing to animate so it counts as not set
+    pub fn get_spinner_frames(&self) -> Option<&[String]> {
+        self.spinner_frames
+            .as_deref()
+            .filter(|frames| !frames.is_empty())
+    }
+
+    pub fn get_loading_text(&self) -> &str {
+        self.loading_text.as_deref().unwrap_or(DEFAULT_LOADING_TEXT)
+    }
+
+    // names of the menu items in the order they should be shown, validated by the menu itself
+    pub fn get_menu_order(&self) -> Option<&[String]> {
+        self.menu_order.as_deref()
+    }
+
+    // names of the menu items that should be shown, all of them if this is not set
+    pub fn get_enabled_views(&self) -> Option<&[String]> {
+        self.enabled_views.as_deref()
+    }
+
     pub fn initialize(&mut self, path: String) {
         self.github_token_file_path = path;
         self.is_default_config = false;
@@ -65,3 +133,30 @@ pub fn create_config(config: Config) -> Result<(), Box<dyn std::error::Error>> {
         None => Err("Couldn't determine config directory".into()),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_spinner_frames_are_not_set() {
+        assert_eq!(Config::new().get_spinner_frames(), None);
+    }
+
+    #[test]
+    fn empty_spinner_frames_are_not_set() {
+        let mut config = Config::new();
+        config.spinner_frames = Some(Vec::new());
+        assert_eq!(config.get_spinner_frames(), None);
+    }
+
+    #[test]
+    fn configured_spinner_frames_are_used() {
+        let mut config = Config::new();
+        config.spinner_frames = Some(vec!["-".to_string(), "|".to_string()]);
+        assert_eq!(
+            config.get_spinner_frames(),
+            Some(["-".to_string(), "|".to_string()].as_slice())
+        );
+    }
+}
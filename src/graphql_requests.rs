@@ -1,5 +1,5 @@
 pub mod github {
-    use std::{error::Error, fs::File, io::Write};
+    use std::error::Error;
 
     use graphql_client::{GraphQLQuery, Response};
     use reqwest::header;
@@ -9,13 +9,47 @@ pub mod github {
     pub mod types {
         use serde::{Deserialize, Serialize};
 
-        use std::time::Duration;
-
         #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
         pub struct User(pub String);
 
-        #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-        pub struct DateTime(pub Duration);
+        // github sends ISO-8601 timestamps in UTC like 2024-05-01T12:00:00Z, those compare
+        // correctly as plain strings so the derived ordering sorts them chronologically
+        #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord)]
+        pub struct DateTime(pub String);
+
+        #[cfg(test)]
+        mod tests {
+            use super::*;
+
+            fn date_time(timestamp: &str) -> DateTime {
+                DateTime(timestamp.to_string())
+            }
+
+            #[test]
+            fn date_times_order_chronologically() {
+                assert!(date_time("2024-05-01T12:00:00Z") < date_time("2024-05-01T12:00:01Z"));
+                assert!(date_time("2024-05-01T23:59:59Z") < date_time("2024-05-02T00:00:00Z"));
+                assert!(date_time("2023-12-31T23:59:59Z") < date_time("2024-01-01T00:00:00Z"));
+            }
+
+            #[test]
+            fn date_times_sort_oldest_first() {
+                let mut date_times = vec![
+                    date_time("2024-06-01T00:00:00Z"),
+                    date_time("2023-01-15T08:30:00Z"),
+                    date_time("2024-05-31T23:00:00Z"),
+                ];
+                date_times.sort();
+                assert_eq!(
+                    date_times,
+                    vec![
+                        date_time("2023-01-15T08:30:00Z"),
+                        date_time("2024-05-31T23:00:00Z"),
+                        date_time("2024-06-01T00:00:00Z"),
+                    ]
+                );
+            }
+        }
     }
 
     #[derive(GraphQLQuery)]
@@ -29,11 +63,20 @@ pub mod github {
 
     pub async fn perform_issue_query(
         variables: issue_query::Variables,
+        user_agent: &str,
     ) -> Result<Option<issue_query::ResponseData>, Box<dyn Error>> {
-        let request_body = IssueQuery::build_query(variables);
+        execute_query::<IssueQuery>(variables, user_agent).await
+    }
+
+    // every query goes through here so client setup and response handling only live in one place
+    async fn execute_query<Q: GraphQLQuery>(
+        variables: Q::Variables,
+        user_agent: &str,
+    ) -> Result<Option<Q::ResponseData>, Box<dyn Error>> {
+        let request_body = Q::build_query(variables);
 
         let client = reqwest::Client::builder()
-            .user_agent("LazyIssues/0.1.0")
+            .user_agent(user_agent)
             .default_headers({
                 let mut headers = header::HeaderMap::new();
                 headers.insert(
@@ -51,11 +94,7 @@ pub mod github {
             .await?;
 
         let text = response.text().await?;
-        if let Ok(mut file) = File::create("out.txt") {
-            file.write_all(text.as_bytes());
-            file.flush();
-        }
-        let response_body: Response<issue_query::ResponseData> = serde_json::from_str(&text)?; //response.json().await?;
+        let response_body: Response<Q::ResponseData> = serde_json::from_str(&text)?; //response.json().await?;
         Ok(response_body.data)
     }
 }
@@ -11,14 +11,19 @@ use std::{
 
 use ratatui::{
     crossterm::{
-        event::{self, Event as CrossEvent, KeyCode},
+        event::{
+            self, DisableBracketedPaste, Event as CrossEvent, KeyCode, KeyEvent, KeyModifiers,
+        },
+        execute,
         terminal::disable_raw_mode,
     },
     layout::{Constraint, Direction, Layout, Rect},
     prelude::CrosstermBackend,
+    style::{Color, Style},
+    widgets::Paragraph,
     Frame, Terminal,
 };
-use ui::UiStack;
+use ui::{toast::ToastKind, UiStack};
 
 mod config;
 mod graphql_requests;
@@ -34,6 +39,7 @@ pub enum Event<I> {
 pub enum Signal {
     Quit,
     ErrorOccured(String),
+    Notification(String),
 }
 
 pub struct EventLoop {
@@ -73,8 +79,8 @@ impl EventLoop {
 
     fn handle_event(&self) {
         match event::read() {
-            Ok(CrossEvent::Key(key)) => {
-                match self.sender.send(Event::Input(CrossEvent::Key(key))) {
+            Ok(event @ (CrossEvent::Key(_) | CrossEvent::Paste(_) | CrossEvent::Resize(_, _))) => {
+                match self.sender.send(Event::Input(event)) {
                     Err(error) => println!("{error} occured during sending!"),
                     _ => (),
                 }
@@ -123,7 +129,7 @@ impl TerminalApp {
             return;
         }
 
-        let mut config = match config::read_config() {
+        let config = match config::read_config() {
             Ok(Some(config)) => config,
             Ok(None) => config::Config::new(),
             Err(error) => {
@@ -132,34 +138,57 @@ impl TerminalApp {
             }
         };
 
-        let mut ui_stack = self.create_ui(UiStack::new());
+        ui::init_colors(config.is_no_color());
+
+        let (toast_sender, toast_receiver) = mpsc::channel();
+        let mut ui_stack = self.create_ui(UiStack::new(), Rc::new(config), toast_receiver);
+        let mut needs_redraw = true;
 
         loop {
-            for panel in ui_stack.iter_rev() {
-                panel.tick();
-            }
+            if needs_redraw {
+                let _ = self.terminal.draw(|render_frame| {
+                    let layout = Self::create_base_layout(render_frame);
 
-            let _ = self.terminal.draw(|render_frame| {
-                let layout = Self::create_base_layout(render_frame);
+                    for panel in ui_stack.iter() {
+                        panel.render(render_frame, &layout)
+                    }
 
-                for panel in ui_stack.iter() {
-                    panel.render(render_frame, &layout)
-                }
-            });
+                    let hints = Self::collect_key_hints(&mut ui_stack);
+                    Self::render_key_hints(render_frame, &hints, layout[2]);
+                });
+                needs_redraw = false;
+            }
 
             match self.input_receiver.recv() {
                 Ok(event) => match event {
                     Event::Input(event) => match event {
                         CrossEvent::Key(key) => {
+                            needs_redraw = true;
                             for panel in ui_stack.iter() {
-                                if panel.handle_input(key) {
+                                if panel.handle_input(key)
+                                    || (panel.has_focus() && is_text_input(&key))
+                                {
                                     break;
                                 }
                             }
                         }
+                        CrossEvent::Paste(text) => {
+                            needs_redraw = true;
+                            for panel in ui_stack.iter() {
+                                if panel.handle_paste(&text) {
+                                    break;
+                                }
+                            }
+                        }
+                        CrossEvent::Resize(_, _) => needs_redraw = true,
                         _ => (),
                     },
-                    Event::Tick => {}
+                    // panels only tick on Event::Tick so anything animated advances at TICK_RATE
+                    Event::Tick => {
+                        for panel in ui_stack.iter_rev() {
+                            needs_redraw |= panel.tick();
+                        }
+                    }
                 },
                 Err(error) => {
                     self.clean_up_terminal(Some(format!(
@@ -175,14 +204,29 @@ impl TerminalApp {
                         self.clean_up_terminal(None);
                         break;
                     }
-                    Signal::ErrorOccured(_message) => {}
+                    Signal::ErrorOccured(message) => {
+                        if let Err(error) = toast_sender.send((ToastKind::Error, message)) {
+                            println!("{error} occured during forwarding of an error!");
+                        }
+                    }
+                    Signal::Notification(message) => {
+                        if let Err(error) = toast_sender.send((ToastKind::Notification, message)) {
+                            println!("{error} occured during forwarding of a notification!");
+                        }
+                    }
                 }
             }
         }
     }
 
-    fn create_ui(&self, mut ui_stack: UiStack) -> UiStack {
-        let tab_menu = ui::tab_menu::TabMenu::new(0, self.signal_sender_cloner.clone());
+    fn create_ui(
+        &self,
+        mut ui_stack: UiStack,
+        config: Rc<config::Config>,
+        toast_receiver: mpsc::Receiver<(ToastKind, String)>,
+    ) -> UiStack {
+        let tab_menu =
+            ui::tab_menu::TabMenu::new(0, self.signal_sender_cloner.clone(), Rc::clone(&config));
         ui_stack.add_panel(tab_menu, 10);
 
         match ui::file_explorer::FileExplorer::new(1) {
@@ -190,6 +234,10 @@ impl TerminalApp {
             Err(error) => println!("{error} occured during creation of file explorer!"),
         }
 
+        // highest priority so it renders on top of everything else
+        let toast = ui::toast::Toast::new(1, toast_receiver);
+        ui_stack.add_panel(toast, 20);
+
         ui_stack
     }
 
@@ -197,11 +245,51 @@ impl TerminalApp {
         let size = render_frame.area();
         Layout::default()
             .direction(ratatui::layout::Direction::Vertical)
-            .constraints([Constraint::Length(3), Constraint::Min(2)].as_ref())
+            .constraints(
+                [
+                    Constraint::Length(3),
+                    Constraint::Min(2),
+                    Constraint::Length(1),
+                ]
+                .as_ref(),
+            )
             .split(size)
     }
 
+    // a focused panel keeps its own keys in the footer, otherwise every panel receives keys so
+    // all hints are shown. keys that work everywhere are always listed
+    fn collect_key_hints(ui_stack: &mut UiStack) -> String {
+        let focused_hints = ui_stack
+            .iter()
+            .find(|panel| panel.has_focus())
+            .map(|panel| panel.key_hints());
+
+        let mut hints: Vec<String> = match focused_hints {
+            Some(focused_hints) => focused_hints.into_iter().collect(),
+            None => ui_stack
+                .iter()
+                .filter_map(|panel| panel.key_hints())
+                .collect(),
+        };
+        hints.extend(
+            ui_stack
+                .iter()
+                .filter_map(|panel| panel.global_key_hints())
+                .map(String::from),
+        );
+
+        hints.join(" · ")
+    }
+
+    fn render_key_hints(render_frame: &mut Frame, hints: &str, chunk: Rect) {
+        let footer = Paragraph::new(hints).style(Style::default().fg(ui::color(Color::DarkGray)));
+        render_frame.render_widget(footer, chunk);
+    }
+
     fn clean_up_terminal(&mut self, message: Option<String>) {
+        if let Err(error) = execute!(io::stdout(), DisableBracketedPaste) {
+            println!("{error} occured when trying to disable bracketed paste!");
+        }
         if let Err(error) = disable_raw_mode() {
             println!("{error} occured when trying to exit raw mode!");
         }
@@ -216,6 +304,11 @@ impl TerminalApp {
     }
 }
 
+fn is_text_input(key: &KeyEvent) -> bool {
+    matches!(key.code, KeyCode::Char(_))
+        && (key.modifiers == KeyModifiers::NONE || key.modifiers == KeyModifiers::SHIFT)
+}
+
 fn create_floating_layout(width: u16, height: u16, base_chunk: Rect) -> Rect {
     let y_offset = 50 - height / 2;
     let x_offset = 50 - width / 2;
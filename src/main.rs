@@ -1,6 +1,6 @@
-use std::{sync::mpsc, thread};
+use std::{io, sync::mpsc, thread};
 
-use ratatui::crossterm::terminal::enable_raw_mode;
+use ratatui::crossterm::{event::EnableBracketedPaste, execute, terminal::enable_raw_mode};
 use rust_issue_handler::{EventLoop, TerminalApp};
 
 fn main() {
@@ -9,6 +9,10 @@ fn main() {
 
 fn setup_terminal() {
     enable_raw_mode().expect("Can run in raw mode");
+    // without this a pasted newline arrives as an Enter key press
+    if let Err(error) = execute!(io::stdout(), EnableBracketedPaste) {
+        println!("{error} occured when trying to enable bracketed paste!");
+    }
 
     let (sender, receiver) = mpsc::channel();
     let mut event_loop = EventLoop::new(sender);
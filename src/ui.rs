@@ -1,14 +1,40 @@
-use std::{collections::BTreeMap, rc::Rc};
+use std::{collections::BTreeMap, rc::Rc, sync::OnceLock};
 
-use ratatui::{crossterm::event::KeyEvent, layout::Rect, Frame};
+use ratatui::{
+    crossterm::event::KeyEvent,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    Frame,
+};
 
 pub mod file_explorer;
+pub mod spinner;
 pub mod tab_menu;
+pub mod toast;
 
 pub trait PanelElement {
     fn handle_input(&mut self, key_event: KeyEvent) -> bool;
+    // pasted text arrives in one piece, panels that don't take text input can ignore it
+    fn handle_paste(&mut self, _text: &str) -> bool {
+        false
+    }
     fn render(&mut self, render_frame: &mut Frame, layout: &Rc<[Rect]>) -> ();
-    fn tick(&mut self) -> ();
+    // a focused panel keeps plain characters from reaching panels further down the stack, so
+    // typing a q into a mask doesn't quit the app
+    fn has_focus(&self) -> bool {
+        false
+    }
+
+    // short summary of the panels keys for the footer line
+    fn key_hints(&self) -> Option<String> {
+        None
+    }
+    // keys that reach the panel even while another panel has focus
+    fn global_key_hints(&self) -> Option<&'static str> {
+        None
+    }
+    // returns true if the panel changed and the frame has to be redrawn
+    fn tick(&mut self) -> bool;
 }
 
 pub struct UiStack {
@@ -34,3 +60,36 @@ impl UiStack {
         self.panels.values_mut().rev()
     }
 }
+
+static COLORS_ENABLED: OnceLock<bool> = OnceLock::new();
+
+// see https://no-color.org, the variable only counts if it is set to something non empty
+fn no_color_env_set() -> bool {
+    std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty())
+}
+
+// has to run before the first frame is drawn, after that the setting is fixed
+pub fn init_colors(no_color_config: bool) {
+    let _ = COLORS_ENABLED.set(!no_color_config && !no_color_env_set());
+}
+
+pub fn colors_enabled() -> bool {
+    *COLORS_ENABLED.get_or_init(|| !no_color_env_set())
+}
+
+pub fn color(color: Color) -> Color {
+    if colors_enabled() {
+        color
+    } else {
+        Color::Reset
+    }
+}
+
+// without colors a highlight is only visible if it changes something other than the color
+pub fn highlight_style(style: Style) -> Style {
+    if colors_enabled() {
+        style
+    } else {
+        Style::default().add_modifier(Modifier::REVERSED)
+    }
+}
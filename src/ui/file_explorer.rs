@@ -14,15 +14,22 @@ use ratatui::{
     Frame,
 };
 
-use crate::{create_floating_layout, ui::PanelElement};
+use crate::{
+    create_floating_layout,
+    ui::{color, highlight_style, PanelElement},
+};
 
 pub struct FileExplorer {
     current_path: PathBuf,
     path_mask: String,
     items: Vec<PathBuf>,
     state: ListState,
+    // number of entries visible at once, updated on every render
+    page_size: usize,
 
     layout_position: usize,
+    // the explorer only takes input and renders while it is open
+    is_open: bool,
 
     cursor_flicker_delay: Duration,
     last_cursor_flicker: Instant,
@@ -37,8 +44,10 @@ impl FileExplorer {
             path_mask: String::from(""),
             items: Vec::new(),
             state: ListState::default(),
+            page_size: 1,
 
             layout_position,
+            is_open: false,
 
             cursor_flicker_delay: Duration::from_millis(300),
             last_cursor_flicker: Instant::now(),
@@ -55,7 +64,7 @@ impl FileExplorer {
             .collect()
     }
 
-    fn update_items(&mut self) -> io::Result<()> {
+    fn read_items(&mut self) -> io::Result<()> {
         self.items = fs::read_dir(&self.current_path)?
             .filter_map(|entry| entry.ok().map(|entry| entry.path()))
             .filter(|entry| self.compare_entry_to_mask(entry.to_str().unwrap_or_default()))
@@ -63,10 +72,32 @@ impl FileExplorer {
 
         self.items.sort();
         self.items.insert(0, "..".into());
+        Ok(())
+    }
+
+    fn update_items(&mut self) -> io::Result<()> {
+        self.read_items()?;
         self.state.select(Some(0));
         Ok(())
     }
 
+    // used when only the mask changed, the selected entry stays selected if it still matches,
+    // otherwise the entry closest to the old position is selected
+    fn filter_items(&mut self) -> io::Result<()> {
+        let selected_index = self.state.selected();
+        let selected_path = selected_index.and_then(|index| self.items.get(index).cloned());
+
+        self.read_items()?;
+
+        let last_index = self.items.len().saturating_sub(1);
+        let entry_index = selected_path
+            .and_then(|selected_path| self.items.iter().position(|item| *item == selected_path))
+            .or(selected_index.map(|index| index.min(last_index)))
+            .unwrap_or(0);
+        self.state.select(Some(entry_index));
+        Ok(())
+    }
+
     fn enter_dir(&mut self) -> io::Result<()> {
         match self.state.selected() {
             Some(selected) => {
@@ -112,6 +143,32 @@ impl FileExplorer {
         self.state.select(Some(entry_index));
     }
 
+    // unlike next_entry and previous_entry these stop at the ends of the list instead of wrapping
+    fn next_page(&mut self) {
+        let last_index = self.items.len().saturating_sub(1);
+        let entry_index = match self.state.selected() {
+            Some(index) => (index + self.page_size).min(last_index),
+            None => 0,
+        };
+        self.state.select(Some(entry_index));
+    }
+
+    fn previous_page(&mut self) {
+        let entry_index = match self.state.selected() {
+            Some(index) => index.saturating_sub(self.page_size),
+            None => 0,
+        };
+        self.state.select(Some(entry_index));
+    }
+
+    fn first_entry(&mut self) {
+        self.state.select(Some(0));
+    }
+
+    fn last_entry(&mut self) {
+        self.state.select(Some(self.items.len().saturating_sub(1)));
+    }
+
     fn compare_entry_to_mask(&self, entry: &str) -> bool {
         if entry.contains(
             &(self.current_path.to_str().unwrap_or_default().to_owned() + "/" + &self.path_mask),
@@ -125,7 +182,16 @@ impl FileExplorer {
     fn add_to_mask(&mut self, char: char) -> io::Result<()> {
         self.path_mask += &char.to_string();
 
-        self.update_items()?;
+        self.filter_items()?;
+        Ok(())
+    }
+
+    fn paste_into_mask(&mut self, text: &str) -> io::Result<()> {
+        // a path mask can't span lines so only the first one is used, terminals send pasted
+        // newlines as \r as often as \n
+        self.path_mask += text.split(['\r', '\n']).next().unwrap_or_default();
+
+        self.filter_items()?;
         Ok(())
     }
 
@@ -137,7 +203,7 @@ impl FileExplorer {
 
         self.path_mask.remove(self.path_mask.len() - 1);
 
-        self.update_items()?;
+        self.filter_items()?;
         Ok(())
     }
 
@@ -167,7 +233,7 @@ impl FileExplorer {
         self.path_mask.clear();
     }
 
-    fn render_cursor(&mut self) -> &str {
+    fn flicker_cursor(&mut self) -> bool {
         let should_switch_mode =
             Instant::now() - self.last_cursor_flicker > self.cursor_flicker_delay;
 
@@ -176,6 +242,10 @@ impl FileExplorer {
             self.last_cursor_flicker = Instant::now();
         }
 
+        should_switch_mode
+    }
+
+    fn render_cursor(&self) -> &str {
         if self.cursor_rendered_last_flicker {
             return "_";
         } else {
@@ -186,12 +256,26 @@ impl FileExplorer {
 
 impl PanelElement for FileExplorer {
     fn handle_input(&mut self, key_event: KeyEvent) -> bool {
+        if !self.is_open {
+            if key_event.code == KeyCode::Char('f') && key_event.modifiers == KeyModifiers::NONE {
+                self.is_open = true;
+                return true;
+            }
+
+            return false;
+        }
+
         match key_event {
             KeyEvent {
                 modifiers: KeyModifiers::NONE,
                 ..
             } => match key_event.code {
+                KeyCode::Esc => self.is_open = false,
                 KeyCode::Tab => self.next_entry(),
+                KeyCode::PageDown => self.next_page(),
+                KeyCode::PageUp => self.previous_page(),
+                KeyCode::Home => self.first_entry(),
+                KeyCode::End => self.last_entry(),
                 KeyCode::Enter => match self.enter_dir() {
                     Err(error) => println!("{error} occured during switching directory!"),
                     _ => (),
@@ -211,6 +295,11 @@ impl PanelElement for FileExplorer {
                 ..
             } => match key_event.code {
                 KeyCode::BackTab => self.previous_entry(),
+                // uppercase letters arrive with SHIFT set and belong in the mask as well
+                KeyCode::Char(char) => match self.add_to_mask(char) {
+                    Err(error) => println!("{error} occured during adding to mask!"),
+                    _ => (),
+                },
                 _ => (),
             },
             _ => (),
@@ -219,11 +308,42 @@ impl PanelElement for FileExplorer {
         false
     }
 
+    fn handle_paste(&mut self, text: &str) -> bool {
+        if !self.is_open {
+            return false;
+        }
+
+        if let Err(error) = self.paste_into_mask(text) {
+            println!("{error} occured during pasting into mask!");
+        }
+
+        true
+    }
+
+    fn has_focus(&self) -> bool {
+        self.is_open
+    }
+
+    fn key_hints(&self) -> Option<String> {
+        if !self.is_open {
+            return Some("f files".to_string());
+        }
+
+        Some(
+            "Tab/S-Tab move · PgUp/PgDn page · Home/End jump · Enter open · type to filter · Esc close"
+                .to_string(),
+        )
+    }
+
     fn render(&mut self, render_frame: &mut Frame, layout: &Rc<[Rect]>) {
+        if !self.is_open {
+            return;
+        }
+
         let directory_items = self.items_as_str();
 
         let display_rect = List::new(directory_items)
-            .highlight_style(Style::default().bg(Color::DarkGray))
+            .highlight_style(highlight_style(Style::default().bg(Color::DarkGray)))
             .block(
                 Block::default()
                     .title(
@@ -234,16 +354,72 @@ impl PanelElement for FileExplorer {
                     )
                     .borders(Borders::ALL),
             )
-            .style(Style::default().fg(Color::White));
+            .style(Style::default().fg(color(Color::White)));
+
+        let explorer_rect = create_floating_layout(50, 50, layout[self.layout_position]);
+        // the border takes up one row at the top and one at the bottom
+        self.page_size = usize::from(explorer_rect.height.saturating_sub(2)).max(1);
+
+        render_frame.render_stateful_widget(display_rect, explorer_rect, &mut self.state);
+    }
+
+    fn tick(&mut self) -> bool {
+        self.is_open && self.flicker_cursor()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn explorer_with_items(item_count: usize, page_size: usize) -> FileExplorer {
+        FileExplorer {
+            current_path: PathBuf::from("."),
+            path_mask: String::new(),
+            items: (0..item_count)
+                .map(|index| PathBuf::from(index.to_string()))
+                .collect(),
+            state: ListState::default().with_selected(Some(0)),
+            page_size,
+            layout_position: 0,
+            is_open: true,
+            cursor_flicker_delay: Duration::from_millis(500),
+            last_cursor_flicker: Instant::now(),
+            cursor_rendered_last_flicker: false,
+        }
+    }
+
+    #[test]
+    fn next_page_stops_at_last_entry() {
+        let mut explorer = explorer_with_items(12, 5);
+        explorer.next_page();
+        assert_eq!(explorer.state.selected(), Some(5));
+        explorer.next_page();
+        assert_eq!(explorer.state.selected(), Some(10));
+        explorer.next_page();
+        assert_eq!(explorer.state.selected(), Some(11));
+        explorer.next_page();
+        assert_eq!(explorer.state.selected(), Some(11));
+    }
 
-        render_frame.render_stateful_widget(
-            display_rect,
-            create_floating_layout(50, 50, layout[self.layout_position]),
-            &mut self.state,
-        );
+    #[test]
+    fn previous_page_stops_at_first_entry() {
+        let mut explorer = explorer_with_items(12, 5);
+        explorer.state.select(Some(7));
+        explorer.previous_page();
+        assert_eq!(explorer.state.selected(), Some(2));
+        explorer.previous_page();
+        assert_eq!(explorer.state.selected(), Some(0));
+        explorer.previous_page();
+        assert_eq!(explorer.state.selected(), Some(0));
     }
 
-    fn tick(&mut self) -> () {
-        ()
+    #[test]
+    fn paging_an_empty_list_selects_the_first_slot() {
+        let mut explorer = explorer_with_items(0, 5);
+        explorer.next_page();
+        assert_eq!(explorer.state.selected(), Some(0));
+        explorer.previous_page();
+        assert_eq!(explorer.state.selected(), Some(0));
     }
 }
@@ -0,0 +1,65 @@
+const BRAILLE_FRAMES: [&str; 8] = ["⣾", "⣽", "⣻", "⢿", "⡿", "⣟", "⣯", "⣷"];
+
+pub struct Spinner {
+    frames: Vec<String>,
+    frame_index: usize,
+}
+
+impl Spinner {
+    // falls back to the braille frames when no frames are configured
+    pub fn new(frames: Option<&[String]>) -> Self {
+        let frames = match frames {
+            Some(frames) => frames.to_vec(),
+            None => BRAILLE_FRAMES
+                .iter()
+                .map(|frame| frame.to_string())
+                .collect(),
+        };
+
+        Self {
+            frames,
+            frame_index: 0,
+        }
+    }
+
+    // should only be called from a panels tick so the animation speed follows TICK_RATE
+    pub fn advance(&mut self) {
+        if self.frames.is_empty() {
+            return;
+        }
+
+        self.frame_index = (self.frame_index + 1) % self.frames.len();
+    }
+
+    pub fn reset(&mut self) {
+        self.frame_index = 0;
+    }
+
+    pub fn glyph(&self) -> &str {
+        self.frames
+            .get(self.frame_index)
+            .map(String::as_str)
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn falls_back_to_braille_frames() {
+        let spinner = Spinner::new(None);
+        assert_eq!(spinner.glyph(), BRAILLE_FRAMES[0]);
+    }
+
+    #[test]
+    fn advance_wraps_around() {
+        let frames = ["a".to_string(), "b".to_string()];
+        let mut spinner = Spinner::new(Some(&frames));
+        spinner.advance();
+        assert_eq!(spinner.glyph(), "b");
+        spinner.advance();
+        assert_eq!(spinner.glyph(), "a");
+    }
+}
@@ -1,4 +1,10 @@
-use std::{collections::HashMap, rc::Rc, sync::mpsc, thread};
+use std::{
+    collections::HashMap,
+    rc::Rc,
+    sync::mpsc,
+    thread,
+    time::{Duration, Instant},
+};
 
 use ratatui::{
     crossterm::event::{KeyCode, KeyEvent, KeyModifiers},
@@ -11,12 +17,13 @@ use ratatui::{
 use tokio::runtime::Runtime;
 
 use crate::{
+    config::Config,
     graphql_requests::github::{issue_query, perform_issue_query},
-    ui::PanelElement,
+    ui::{color, highlight_style, spinner::Spinner, PanelElement},
     Signal,
 };
 
-#[derive(Hash, PartialEq, Eq)]
+#[derive(Debug, Hash, PartialEq, Eq, Clone)]
 pub enum MenuItem {
     Issues,
     IssueView,
@@ -50,13 +57,87 @@ impl From<&MenuItem> for String {
 }
 
 impl MenuItem {
-    fn to_string_array() -> [String; 4] {
-        return [
-            "Issues".to_string(),
-            "Pull requests".to_string(),
-            "Actions".to_string(),
-            "Projects".to_string(),
-        ];
+    fn main_menu_items() -> [MenuItem; 4] {
+        [
+            MenuItem::Issues,
+            MenuItem::PullRequests,
+            MenuItem::Actions,
+            MenuItem::Projects,
+        ]
+    }
+
+    fn key_hint(&self) -> Option<&'static str> {
+        match self {
+            MenuItem::Issues => Some("I issues"),
+            MenuItem::PullRequests => Some("P pull requests"),
+            MenuItem::Actions => Some("A actions"),
+            MenuItem::Projects => Some("r projects"),
+            _ => None,
+        }
+    }
+
+    fn from_config_name(name: &str) -> Option<MenuItem> {
+        match name {
+            "issues" => Some(MenuItem::Issues),
+            "pull_requests" => Some(MenuItem::PullRequests),
+            "actions" => Some(MenuItem::Actions),
+            "projects" => Some(MenuItem::Projects),
+            _ => None,
+        }
+    }
+
+    // the order has to name every main menu item exactly once
+    fn ordered_from_config(order: Option<&[String]>) -> Result<Vec<MenuItem>, String> {
+        let Some(order) = order else {
+            return Ok(Self::main_menu_items().to_vec());
+        };
+
+        let mut menu_items = Vec::new();
+        for name in order {
+            let menu_item = Self::from_config_name(name)
+                .ok_or_else(|| format!("Unknown menu item {name} in menu_order!"))?;
+            if menu_items.contains(&menu_item) {
+                return Err(format!("Menu item {name} appears twice in menu_order!"));
+            }
+            menu_items.push(menu_item);
+        }
+
+        if menu_items.len() != Self::main_menu_items().len() {
+            return Err(
+                "menu_order has to contain issues, pull_requests, actions and projects!"
+                    .to_string(),
+            );
+        }
+
+        Ok(menu_items)
+    }
+
+    // keeps the order of menu_items, an empty selection would leave nothing to show
+    fn enabled_from_config(
+        menu_items: Vec<MenuItem>,
+        enabled_views: Option<&[String]>,
+    ) -> Result<Vec<MenuItem>, String> {
+        let Some(enabled_views) = enabled_views else {
+            return Ok(menu_items);
+        };
+
+        let mut enabled_items = Vec::new();
+        for name in enabled_views {
+            let menu_item = Self::from_config_name(name)
+                .ok_or_else(|| format!("Unknown view {name} in enabled_views!"))?;
+            enabled_items.push(menu_item);
+        }
+
+        let menu_items: Vec<MenuItem> = menu_items
+            .into_iter()
+            .filter(|menu_item| enabled_items.contains(menu_item))
+            .collect();
+
+        if menu_items.is_empty() {
+            return Err("enabled_views has to enable at least one view!".to_string());
+        }
+
+        Ok(menu_items)
     }
 }
 
@@ -66,6 +147,8 @@ pub enum QueryData {
 
 pub struct TabMenu {
     active_menu_item: MenuItem,
+    // main menu items in the order they are shown
+    menu_items: Vec<MenuItem>,
 
     layout_position: usize,
 
@@ -74,21 +157,60 @@ pub struct TabMenu {
 
     // this might be a stupid way to store this
     query_response_data: HashMap<MenuItem, QueryData>,
+    fetched_at: HashMap<MenuItem, Instant>,
+
+    // age of the active views data as it was last rendered, so we only redraw when it changes
+    displayed_refresh_age: Option<String>,
+
+    issues_request: Option<thread::JoinHandle<()>>,
+    loading_spinner: Spinner,
 
     signal_sender: mpsc::Sender<Signal>,
+
+    config: Rc<Config>,
 }
 
 impl TabMenu {
-    pub fn new(layout_position: usize, signal_sender: mpsc::Sender<Signal>) -> Self {
+    pub fn new(
+        layout_position: usize,
+        signal_sender: mpsc::Sender<Signal>,
+        config: Rc<Config>,
+    ) -> Self {
         let (query_clone_sender, query_receiver) = mpsc::channel();
 
+        let menu_items = match MenuItem::ordered_from_config(config.get_menu_order()) {
+            Ok(menu_items) => menu_items,
+            Err(message) => {
+                let _ = signal_sender.send(Signal::ErrorOccured(format!(
+                    "{message} Using the default order."
+                )));
+                MenuItem::main_menu_items().to_vec()
+            }
+        };
+        let menu_items =
+            match MenuItem::enabled_from_config(menu_items.clone(), config.get_enabled_views()) {
+                Ok(menu_items) => menu_items,
+                Err(message) => {
+                    let _ = signal_sender.send(Signal::ErrorOccured(format!(
+                        "{message} Showing all views."
+                    )));
+                    menu_items
+                }
+            };
+
         Self {
-            active_menu_item: MenuItem::Issues,
+            active_menu_item: menu_items[0].clone(),
+            menu_items,
             layout_position,
             query_receiver,
             query_clone_sender,
             query_response_data: HashMap::new(),
+            fetched_at: HashMap::new(),
+            displayed_refresh_age: None,
+            issues_request: None,
+            loading_spinner: Spinner::new(config.get_spinner_frames()),
             signal_sender,
+            config,
         }
     }
 
@@ -126,106 +248,356 @@ impl TabMenu {
 
             let issue_list = List::new(issue_items)
                 .block(Block::default().title("Issues").borders(Borders::ALL))
-                .style(Style::default().fg(Color::White));
+                .style(Style::default().fg(color(Color::White)));
 
             render_frame.render_widget(issue_list, chunk);
         }
     }
 
-    async fn fetch_issues(sender: mpsc::Sender<(MenuItem, QueryData)>) {
+    // disabled views can't be selected, returns whether the selection happened
+    fn select_menu_item(&mut self, menu_item: MenuItem) -> bool {
+        if !self.menu_items.contains(&menu_item) {
+            return false;
+        }
+
+        self.active_menu_item = menu_item;
+        true
+    }
+
+    fn active_tab_index(&self) -> usize {
+        let active_tab = usize::from(&self.active_menu_item);
+        self.menu_items
+            .iter()
+            .position(|menu_item| usize::from(menu_item) == active_tab)
+            .unwrap_or(0)
+    }
+
+    fn is_loading(&self) -> bool {
+        self.issues_request.is_some()
+    }
+
+    fn menu_title(&self) -> String {
+        let mut title = String::from(&self.active_menu_item);
+        if let Some(age) = self.refresh_age() {
+            title = format!("{title} · {age}");
+        }
+
+        if self.is_loading() {
+            return format!(
+                "{title} {} {}",
+                self.loading_spinner.glyph(),
+                self.config.get_loading_text()
+            );
+        }
+
+        title
+    }
+
+    fn refresh_age(&self) -> Option<String> {
+        let elapsed = self.fetched_at.get(&self.active_menu_item)?.elapsed();
+        Some(format!("updated {} ago", format_age(elapsed)))
+    }
+
+    fn is_fresh(&self, menu_item: &MenuItem) -> bool {
+        self.fetched_at
+            .get(menu_item)
+            .is_some_and(|fetched_at| fetched_at.elapsed() < self.config.get_freshness_window())
+    }
+
+    // only the issues view fetches anything so far
+    fn refresh_active_view(&mut self) {
+        if self.active_menu_item != MenuItem::Issues {
+            return;
+        }
+
+        if self.is_loading() {
+            let _ = self.signal_sender.send(Signal::Notification(
+                "Issues are already being fetched".to_string(),
+            ));
+            return;
+        }
+
+        self.request_issues(true);
+    }
+
+    fn request_issues(&mut self, force: bool) {
+        if self.is_loading() || (!force && self.is_fresh(&MenuItem::Issues)) {
+            return;
+        }
+
+        let cloned_sender = self.query_clone_sender.clone();
+        let cloned_signal_sender = self.signal_sender.clone();
+        // the config itself can't be sent to another thread so only the needed values are cloned
+        let user_agent = self.config.get_user_agent().to_string();
+        self.issues_request = Some(thread::spawn(move || {
+            let runtime = Runtime::new();
+            match runtime {
+                Ok(runtime) => runtime.block_on(async {
+                    Self::fetch_issues(cloned_sender, cloned_signal_sender, &user_agent).await;
+                }),
+                Err(error) => {
+                    let _ = cloned_signal_sender.send(Signal::ErrorOccured(format!(
+                        "{error} occured while creating runtime"
+                    )));
+                }
+            };
+        }));
+    }
+
+    async fn fetch_issues(
+        sender: mpsc::Sender<(MenuItem, QueryData)>,
+        signal_sender: mpsc::Sender<Signal>,
+        user_agent: &str,
+    ) {
         let variables = issue_query::Variables {
             repo_name: "test_repo".to_string(),
             repo_owner: "pkleineb".to_string(),
         };
 
-        let response_data = perform_issue_query(variables).await;
+        let response_data = perform_issue_query(variables, user_agent).await;
 
-        match response_data {
+        let error_message = match response_data {
             Ok(ok) => match ok {
                 Some(data) => match sender.send((MenuItem::Issues, QueryData::IssuesData(data))) {
-                    Err(error) => println!("{error} occured during sending of query data!"),
-                    _ => (),
+                    Err(error) => format!("{error} occured during sending of query data!"),
+                    _ => return,
                 },
-                None => println!("No data fetched from server!"),
+                None => "No data fetched from server!".to_string(),
             },
-            Err(error) => println!("{:?} occured during fetching data from server!", error),
+            Err(error) => format!("{error} occured during fetching data from server!"),
         };
+
+        let _ = signal_sender.send(Signal::ErrorOccured(error_message));
     }
 }
 
 impl PanelElement for TabMenu {
     fn handle_input(&mut self, key_event: KeyEvent) -> bool {
         match key_event {
+            // crossterm reports uppercase letters with SHIFT set
             KeyEvent {
-                modifiers: KeyModifiers::NONE,
+                modifiers: KeyModifiers::NONE | KeyModifiers::SHIFT,
                 ..
             } => match key_event.code {
                 KeyCode::Char('I') => {
-                    self.active_menu_item = MenuItem::Issues;
-                    let cloned_sender = self.query_clone_sender.clone();
-                    thread::spawn(move || {
-                        let runtime = Runtime::new();
-                        match runtime {
-                            Ok(runtime) => runtime.block_on(async {
-                                Self::fetch_issues(cloned_sender).await;
-                            }),
-                            Err(error) => println!("{error} occured while creating runtime"),
-                        };
-                    });
+                    if self.select_menu_item(MenuItem::Issues) {
+                        self.request_issues(false);
+                    }
+                }
+                KeyCode::Char('P') => {
+                    self.select_menu_item(MenuItem::PullRequests);
+                }
+                KeyCode::Char('A') => {
+                    self.select_menu_item(MenuItem::Actions);
+                }
+                KeyCode::Char('r') => {
+                    self.select_menu_item(MenuItem::Projects);
                 }
-                KeyCode::Char('P') => self.active_menu_item = MenuItem::PullRequests,
-                KeyCode::Char('A') => self.active_menu_item = MenuItem::Actions,
-                KeyCode::Char('r') => self.active_menu_item = MenuItem::Projects,
                 KeyCode::Char('q') => {
                     let _ = self.signal_sender.send(Signal::Quit);
                 }
                 _ => (),
             },
+            KeyEvent {
+                modifiers: KeyModifiers::CONTROL,
+                code: KeyCode::Char('r'),
+                ..
+            } => self.refresh_active_view(),
+            KeyEvent {
+                modifiers: KeyModifiers::CONTROL,
+                code: KeyCode::Char('c'),
+                ..
+            } => {
+                let _ = self.signal_sender.send(Signal::Quit);
+            }
             _ => (),
         }
 
         false
     }
 
+    // disabled views can't be selected so their keys are left out
+    fn key_hints(&self) -> Option<String> {
+        let mut hints: Vec<&str> = self
+            .menu_items
+            .iter()
+            .filter_map(|menu_item| menu_item.key_hint())
+            .collect();
+        hints.push("q quit");
+
+        Some(hints.join(" · "))
+    }
+
+    // control keys are never held back by a focused text input
+    fn global_key_hints(&self) -> Option<&'static str> {
+        Some("C-r refresh · C-c quit")
+    }
+
     fn render(&mut self, render_frame: &mut Frame, layout: &Rc<[Rect]>) -> () {
-        let menu_string_items = MenuItem::to_string_array();
-        let menu: Vec<Line> = menu_string_items
+        let menu: Vec<Line> = self
+            .menu_items
             .iter()
-            .map(|title| {
+            .map(|menu_item| {
+                let title = String::from(menu_item);
                 let (first, rest) = title.split_at(1);
                 Line::from(vec![
                     Span::styled(
-                        first,
+                        first.to_string(),
                         Style::default()
-                            .fg(Color::Red)
+                            .fg(color(Color::Red))
                             .add_modifier(Modifier::UNDERLINED),
                     ),
-                    Span::styled(rest, Style::default().fg(Color::White)),
+                    Span::styled(rest.to_string(), Style::default().fg(color(Color::White))),
                 ])
             })
             .collect();
 
         let tabs = Tabs::new(menu)
-            .select((&self.active_menu_item).into())
+            .select(self.active_tab_index())
             .block(
                 Block::default()
-                    .title(String::from(&self.active_menu_item))
+                    .title(self.menu_title())
                     .borders(Borders::ALL),
             )
-            .style(Style::default().fg(Color::White))
-            .highlight_style(Style::default().fg(Color::Red))
+            .style(Style::default().fg(color(Color::White)))
+            .highlight_style(highlight_style(Style::default().fg(Color::Red)))
             .divider(Span::raw("|"));
 
         render_frame.render_widget(tabs, layout[self.layout_position]);
     }
 
-    fn tick(&mut self) -> () {
+    fn tick(&mut self) -> bool {
+        let mut needs_redraw = false;
+
         // try_recv does not block the current thread which is nice here because we don't
         // have a tick signal recv() would block the thread until we receive a message from
         // the sender I am ignoring the error here but that may not be best practice
         if let Ok(data) = self.query_receiver.try_recv() {
-            let (key, value) = data;
+            let (key, mut value) = data;
+            match &mut value {
+                QueryData::IssuesData(data) => sort_issues(data),
+            }
+            self.fetched_at.insert(key.clone(), Instant::now());
             self.query_response_data.insert(key, value);
+            needs_redraw = true;
+        }
+
+        match &self.issues_request {
+            Some(request) if request.is_finished() => {
+                self.issues_request = None;
+                self.loading_spinner.reset();
+                needs_redraw = true;
+            }
+            Some(_) => {
+                self.loading_spinner.advance();
+                needs_redraw = true;
+            }
+            None => (),
         }
+
+        let refresh_age = self.refresh_age();
+        if refresh_age != self.displayed_refresh_age {
+            self.displayed_refresh_age = refresh_age;
+            needs_redraw = true;
+        }
+
+        needs_redraw
+    }
+}
+
+// the server breaks ties in whatever order it likes, sorting here keeps the order stable between
+// refreshes. items without a node end up last
+fn sort_issues(data: &mut issue_query::ResponseData) {
+    if let Some(nodes) = data
+        .repository
+        .as_mut()
+        .and_then(|repository| repository.issues.nodes.as_mut())
+    {
+        nodes.sort_by_key(|node| std::cmp::Reverse(node.as_ref().map(|node| node.number)));
+    }
+}
+
+fn format_age(elapsed: Duration) -> String {
+    let seconds = elapsed.as_secs();
+    match seconds {
+        0..60 => format!("{seconds}s"),
+        60..3600 => format!("{}m", seconds / 60),
+        3600..86400 => format!("{}h", seconds / 3600),
+        _ => format!("{}d", seconds / 86400),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn menu_order_defaults_without_config() {
+        let menu_items = MenuItem::ordered_from_config(None).unwrap();
+        assert_eq!(menu_items, MenuItem::main_menu_items().to_vec());
+    }
+
+    #[test]
+    fn menu_order_follows_config() {
+        let order = names(&["pull_requests", "issues", "projects", "actions"]);
+        let menu_items = MenuItem::ordered_from_config(Some(&order)).unwrap();
+        assert_eq!(
+            menu_items,
+            vec![
+                MenuItem::PullRequests,
+                MenuItem::Issues,
+                MenuItem::Projects,
+                MenuItem::Actions
+            ]
+        );
+    }
+
+    #[test]
+    fn enabled_views_keep_menu_order() {
+        let menu_items = vec![
+            MenuItem::Projects,
+            MenuItem::Issues,
+            MenuItem::PullRequests,
+            MenuItem::Actions,
+        ];
+        let enabled = names(&["issues", "projects"]);
+
+        let menu_items = MenuItem::enabled_from_config(menu_items, Some(&enabled)).unwrap();
+        assert_eq!(menu_items, vec![MenuItem::Projects, MenuItem::Issues]);
+    }
+
+    #[test]
+    fn enabled_views_rejects_unknown_and_empty_selection() {
+        let unknown = names(&["issues", "wiki"]);
+        let empty = names(&[]);
+
+        let menu_items = MenuItem::main_menu_items().to_vec();
+        assert!(MenuItem::enabled_from_config(menu_items.clone(), Some(&unknown)).is_err());
+        assert!(MenuItem::enabled_from_config(menu_items, Some(&empty)).is_err());
+    }
+
+    #[test]
+    fn menu_order_rejects_unknown_duplicate_and_missing_items() {
+        let unknown = names(&["issues", "pull_requests", "actions", "wiki"]);
+        let duplicate = names(&["issues", "issues", "actions", "projects"]);
+        let missing = names(&["issues", "pull_requests", "actions"]);
+
+        assert!(MenuItem::ordered_from_config(Some(&unknown)).is_err());
+        assert!(MenuItem::ordered_from_config(Some(&duplicate)).is_err());
+        assert!(MenuItem::ordered_from_config(Some(&missing)).is_err());
+    }
+
+    #[test]
+    fn format_age_switches_units_at_boundaries() {
+        assert_eq!(format_age(Duration::from_secs(59)), "59s");
+        assert_eq!(format_age(Duration::from_secs(60)), "1m");
+        assert_eq!(format_age(Duration::from_secs(3599)), "59m");
+        assert_eq!(format_age(Duration::from_secs(3600)), "1h");
+        assert_eq!(format_age(Duration::from_secs(86399)), "23h");
+        assert_eq!(format_age(Duration::from_secs(86400)), "1d");
     }
 }
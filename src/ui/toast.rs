@@ -0,0 +1,194 @@
+use std::{
+    collections::VecDeque,
+    rc::Rc,
+    sync::mpsc,
+    time::{Duration, Instant},
+};
+
+use ratatui::{
+    crossterm::event::KeyEvent,
+    layout::Rect,
+    style::{Color, Style},
+    text::Span,
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::ui::{color, PanelElement};
+
+const TOAST_DURATION: Duration = Duration::from_secs(4);
+// one row of border on each side
+const TOAST_BORDER_HEIGHT: u16 = 2;
+
+pub enum ToastKind {
+    Error,
+    Notification,
+}
+
+impl ToastKind {
+    fn title(&self) -> &str {
+        match self {
+            ToastKind::Error => "Error",
+            ToastKind::Notification => "Notification",
+        }
+    }
+
+    fn color(&self) -> Color {
+        match self {
+            ToastKind::Error => Color::Red,
+            ToastKind::Notification => Color::Cyan,
+        }
+    }
+}
+
+pub struct Toast {
+    message_receiver: mpsc::Receiver<(ToastKind, String)>,
+    messages: VecDeque<(ToastKind, String)>,
+    // when the message at the front of the queue was first shown
+    shown_since: Option<Instant>,
+
+    layout_position: usize,
+}
+
+impl Toast {
+    pub fn new(
+        layout_position: usize,
+        message_receiver: mpsc::Receiver<(ToastKind, String)>,
+    ) -> Self {
+        Self {
+            message_receiver,
+            messages: VecDeque::new(),
+            shown_since: None,
+            layout_position,
+        }
+    }
+
+    fn title(kind: &ToastKind, queued: usize) -> String {
+        match queued {
+            0 | 1 => kind.title().to_string(),
+            queued => format!("{} (+{} more)", kind.title(), queued - 1),
+        }
+    }
+
+    // the toast grows with its message but never past the chunk it is drawn in
+    fn toast_rect(base_chunk: Rect, message: &str) -> Rect {
+        let text_width = base_chunk.width.saturating_sub(TOAST_BORDER_HEIGHT);
+        let text_height =
+            u16::try_from(wrapped_line_count(message, text_width)).unwrap_or(u16::MAX);
+        let height = text_height
+            .saturating_add(TOAST_BORDER_HEIGHT)
+            .min(base_chunk.height);
+        Rect {
+            y: base_chunk.y + base_chunk.height - height,
+            height,
+            ..base_chunk
+        }
+    }
+}
+
+// mirrors the word wrapping of Paragraph with Wrap { trim: true } closely enough to size the toast,
+// words longer than the width are split over as many lines as they need
+fn wrapped_line_count(message: &str, width: u16) -> usize {
+    let width = usize::from(width.max(1));
+
+    message
+        .lines()
+        .map(|line| {
+            let mut line_count = 1;
+            let mut line_width = 0;
+            for word in line.split_whitespace() {
+                let word_width = Span::raw(word).width();
+                let needed_width = if line_width == 0 {
+                    word_width
+                } else {
+                    line_width + 1 + word_width
+                };
+
+                if needed_width <= width {
+                    line_width = needed_width;
+                } else if line_width == 0 {
+                    line_count += (word_width - 1) / width;
+                    line_width = (word_width - 1) % width + 1;
+                } else {
+                    line_count += 1 + (word_width.max(1) - 1) / width;
+                    line_width = (word_width.max(1) - 1) % width + 1;
+                }
+            }
+            line_count
+        })
+        .sum::<usize>()
+        .max(1)
+}
+
+impl PanelElement for Toast {
+    // the toast is only informational and never takes input away from other panels
+    fn handle_input(&mut self, _key_event: KeyEvent) -> bool {
+        false
+    }
+
+    fn render(&mut self, render_frame: &mut Frame, layout: &Rc<[Rect]>) {
+        let Some((kind, message)) = self.messages.front() else {
+            return;
+        };
+
+        let toast = Paragraph::new(message.as_str())
+            .wrap(Wrap { trim: true })
+            .block(
+                Block::default()
+                    .title(Self::title(kind, self.messages.len()))
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(color(kind.color()))),
+            )
+            .style(Style::default().fg(color(Color::White)));
+
+        let toast_rect = Self::toast_rect(layout[self.layout_position], message);
+        render_frame.render_widget(Clear, toast_rect);
+        render_frame.render_widget(toast, toast_rect);
+    }
+
+    fn tick(&mut self) -> bool {
+        let mut needs_redraw = false;
+
+        while let Ok(message) = self.message_receiver.try_recv() {
+            self.messages.push_back(message);
+            needs_redraw = true;
+        }
+
+        match self.shown_since {
+            Some(shown_since) if shown_since.elapsed() >= TOAST_DURATION => {
+                self.messages.pop_front();
+                self.shown_since = None;
+                needs_redraw = true;
+            }
+            _ => (),
+        }
+
+        if self.shown_since.is_none() && !self.messages.is_empty() {
+            self.shown_since = Some(Instant::now());
+        }
+
+        needs_redraw
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_message_fits_one_line() {
+        assert_eq!(wrapped_line_count("not found", 20), 1);
+    }
+
+    #[test]
+    fn words_wrap_onto_new_lines() {
+        assert_eq!(wrapped_line_count("aaaa bbbb cccc", 9), 2);
+        assert_eq!(wrapped_line_count("aaaa bbbb\ncccc", 20), 2);
+    }
+
+    #[test]
+    fn long_words_are_split() {
+        assert_eq!(wrapped_line_count("aaaaaaaaaa", 4), 3);
+        assert_eq!(wrapped_line_count("bb aaaaaaaaaa", 4), 4);
+    }
+}